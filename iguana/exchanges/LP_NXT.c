@@ -21,6 +21,8 @@
 
 char *NXTnodes[] = { "62.75.159.113", "91.44.203.238", "82.114.88.225", "78.63.207.76", "188.174.110.224", "91.235.72.49", "213.144.130.91", "209.222.98.250", "216.155.128.10", "178.33.203.157", "162.243.122.251", "69.163.47.173", "193.151.106.129", "78.94.2.74", "192.3.196.10", "173.33.112.87", "104.198.173.28", "35.184.154.126", "174.140.167.239", "23.88.113.131", "198.71.84.173", "178.150.207.53", "23.88.61.53", "192.157.233.106", "192.157.241.212", "23.89.192.88", "23.89.200.27", "192.157.241.139", "23.89.200.63", "23.89.192.98", "163.172.214.102", "176.9.85.5", "80.150.243.88", "80.150.243.92", "80.150.243.98", "109.70.186.198", "146.148.84.237", "104.155.56.82", "104.197.157.140", "37.48.73.249", "146.148.77.226", "84.57.170.200", "107.161.145.131", "80.150.243.97", "80.150.243.93", "80.150.243.100", "80.150.243.95", "80.150.243.91", "80.150.243.99", "80.150.243.96", "93.231.187.177", "212.237.23.85", "35.158.179.254", "46.36.66.41", "185.170.113.79", "163.172.68.112", "78.47.35.210", "77.90.90.75", "94.177.196.134", "212.237.22.215", "94.177.234.11", "167.160.180.199", "54.68.189.9", "94.159.62.14", "195.181.221.89", "185.33.145.94", "195.181.209.245", "195.181.221.38", "195.181.221.162", "185.33.145.12", "185.33.145.176", "178.79.128.235", "94.177.214.120", "94.177.199.41", "94.177.214.200", "94.177.213.201", "212.237.13.162", "195.181.221.236", "195.181.221.185", "185.28.103.187", "185.33.146.244", "217.61.123.71", "195.181.214.45", "195.181.212.99", "195.181.214.46", "195.181.214.215", "195.181.214.68", "217.61.123.118", "195.181.214.79", "217.61.123.14", "217.61.124.100", "195.181.214.111", "85.255.0.176", "81.2.254.116", "217.61.123.184", "195.181.212.231", "94.177.214.110", "195.181.209.164", "104.129.56.238", "85.255.13.64", "167.160.180.206", "217.61.123.226", "167.160.180.208", "93.186.253.127", "212.237.6.208", "94.177.207.190", "217.61.123.119", "85.255.1.245", "217.61.124.157", "37.59.57.141", "167.160.180.58", "104.223.53.14", "217.61.124.69", "195.181.212.103", "85.255.13.141", "104.207.133.204", "71.90.7.107", "107.150.18.108", "23.94.134.161", "80.150.243.13", "80.150.243.11", "185.81.165.52", "80.150.243.8" };
 
+char LP_NXTendpoint[128] = { "127.0.0.1:7876" };
+
 static char *assetids[][4] =
 {
     { "13502152099823770958", "SUPERNETx2", "10000", "10000" },
@@ -81,7 +83,7 @@ uint64_t LP_assetid_mult(int32_t *assetindp,char *name,uint64_t assetid)
 cJSON *LP_NXT_message(char *method,uint64_t txnum,char *passphrase)
 {
     char url[1024],*retstr; cJSON *retjson = 0;
-    sprintf(url,"http://127.0.0.1:7876/nxt?requestType=%s&transaction=%llu&secretPhrase=%s",method,(long long)txnum,passphrase);
+    sprintf(url,"http://%s/nxt?requestType=%s&transaction=%llu&secretPhrase=%s",LP_NXTendpoint,method,(long long)txnum,passphrase);
     //printf("issue.(%s)\n",url);
     if ( (retstr= issue_curlt(url,LP_HTTP_TIMEOUT)) != 0 )
     {
@@ -99,7 +101,7 @@ cJSON *LP_NXT_decrypt(uint64_t txnum,char *account,char *data,char *nonce,char *
     char url[1024],*retstr; cJSON *retjson = 0;
     if ( account != 0 && data != 0 && nonce != 0 && passphrase != 0 )
     {
-        sprintf(url,"http://127.0.0.1:7876/nxt?requestType=readMessage&transaction=%llu&secretPhrase=%s",(long long)txnum,passphrase);
+        sprintf(url,"http://%s/nxt?requestType=readMessage&transaction=%llu&secretPhrase=%s",LP_NXTendpoint,(long long)txnum,passphrase);
         if ( (retstr= issue_curlt(url,LP_HTTP_TIMEOUT)) != 0 )
         {
             if ( (retjson= cJSON_Parse(retstr)) != 0 )
@@ -115,7 +117,7 @@ cJSON *LP_NXT_decrypt(uint64_t txnum,char *account,char *data,char *nonce,char *
 int64_t NXTventure_qty(uint64_t assetid)
 {
     char url[1024],*retstr; uint64_t qty=0; cJSON *retjson;
-    sprintf(url,"http://127.0.0.1:7876/nxt?requestType=getAccountAssets&account=NXT-XRK4-5HYK-5965-9FH4Z&includeAssetInfo=true");
+    sprintf(url,"http://%s/nxt?requestType=getAccountAssets&account=NXT-XRK4-5HYK-5965-9FH4Z&includeAssetInfo=true",LP_NXTendpoint);
     if ( (retstr= issue_curlt(url,LP_HTTP_TIMEOUT)) != 0 )
     {
         printf("NXT_venture_qty(%s)\n",retstr);
@@ -148,9 +150,10 @@ void NXTventure_liquidation()
         { "6932037131189568014", "1495473", "jl777hodl", "1" },
         { "15344649963748848799", "7250", "InstantDEX", "1" },
     };
-    void *cHandle=0; char *retstr,*retstr2,url[1024],*account; uint64_t txid,qty,qtyA,assetid,sum; double ratio; cJSON *array,*retjson2,*item,*retjson; int32_t i,j,decimals,numassetids=(int32_t)(sizeof(assetids)/sizeof(*assetids)),n=0;
+    void *cHandle=0; char *retstr,*retstr2,url[1024],nxturl[256],*account; uint64_t txid,qty,qtyA,assetid,sum; double ratio; cJSON *array,*retjson2,*item,*retjson; int32_t i,j,decimals,numassetids=(int32_t)(sizeof(assetids)/sizeof(*assetids)),n=0;
     char *passphrase = "";
-    sprintf(url,"http://127.0.0.1:7876/nxt?requestType=getAssetAccounts&asset=16212446818542881180");
+    sprintf(nxturl,"http://%s/nxt",LP_NXTendpoint);
+    sprintf(url,"http://%s/nxt?requestType=getAssetAccounts&asset=16212446818542881180",LP_NXTendpoint);
     if ( (retstr= issue_curlt(url,LP_HTTP_TIMEOUT)) != 0 )
     {
         if ( (retjson= cJSON_Parse(retstr)) != 0 )
@@ -175,7 +178,7 @@ void NXTventure_liquidation()
                             {
                                 sum += (long long)(qtyA * ratio);
                                 sprintf(url,"requestType=transferAsset&secretPhrase=%s&recipient=%s&asset=%llu&quantityQNT=%llu&feeNQT=100000000&deadline=60",passphrase,account,(long long)assetid,(long long)(qtyA * ratio));
-                                if ( (retstr2= curl_post(&cHandle,"http://127.0.0.1:7876/nxt","",url,"","","","")) != 0 )
+                                if ( (retstr2= curl_post(&cHandle,nxturl,"",url,"","","","")) != 0 )
                                 {
                                     if ( (retjson2= cJSON_Parse(retstr2)) != 0 )
                                     {
@@ -207,7 +210,7 @@ cJSON *LP_NXT_redeems()
     char *passphrase = "";
     char *account = "NXT-MRBN-8DFH-PFMK-A4DBM";
     memset(totals,0,sizeof(totals));
-    sprintf(url,"http://127.0.0.1:7876/nxt?requestType=getBlockchainTransactions&account=%s",account);
+    sprintf(url,"http://%s/nxt?requestType=getBlockchainTransactions&account=%s",LP_NXTendpoint,account);
     //printf("calling (%s)\n",url);
     if ( (retstr= issue_curlt(url,LP_HTTP_TIMEOUT)) != 0 )
     {
@@ -297,7 +300,7 @@ cJSON *LP_NXT_redeems()
             //free_json(retjson);
         }
         free(retstr);
-    } else printf("null return from NXT api call\n");
+    } else printf("null return from NXT api call, is the NXT client reachable at %s?\n",LP_NXTendpoint);
     printf("\nTotal redeemed.%d\n",numtx);
     for (past_marker=0; past_marker<2; past_marker++)
     {
@@ -379,12 +379,13 @@ struct iguana_info *LP_coinadd(struct iguana_info *cdata)
 }
 
 void *curl_easy_init();
-uint16_t LP_coininit(struct iguana_info *coin,char *symbol,char *name,char *assetname,int32_t isPoS,uint16_t port,uint8_t pubtype,uint8_t p2shtype,uint8_t wiftype,uint64_t txfee,double estimatedrate,int32_t longestchain,uint8_t wiftaddr,uint8_t taddr,uint16_t busport,char *confpath,uint8_t decimals,uint32_t txversion)
+uint16_t LP_coininit(struct iguana_info *coin,char *symbol,char *name,char *assetname,int32_t isPoS,uint16_t port,uint8_t pubtype,uint8_t p2shtype,uint8_t wiftype,uint64_t txfee,double estimatedrate,int32_t longestchain,uint8_t wiftaddr,uint8_t taddr,uint16_t busport,char *confpath,uint8_t decimals,uint32_t txversion,int32_t electrumonly)
 {
     static void *ctx;
     char *name2; uint16_t origport = port;
     memset(coin,0,sizeof(*coin));
     safecopy(coin->symbol,symbol,sizeof(coin->symbol));
+    coin->electrumonly = (electrumonly != 0);
     coin->updaterate = (uint32_t)time(NULL);
     coin->isPoS = isPoS;
     coin->taddr = taddr;
@@ -415,7 +416,8 @@ uint16_t LP_coininit(struct iguana_info *coin,char *symbol,char *name,char *asse
         printf("truncate importprivkey for %s\n",symbol);
     }
 #ifndef FROM_JS
-    port = LP_userpass(coin->userpass,symbol,assetname,name,name2,confpath,port);
+    if ( electrumonly == 0 )
+        port = LP_userpass(coin->userpass,symbol,assetname,name,name2,confpath,port);
 #endif
     sprintf(coin->serverport,"127.0.0.1:%u",port);
     if ( port != origport )
@@ -462,7 +464,7 @@ int32_t LP_isdisabled(char *base,char *rel)
     else return(0);
 }
 
-struct iguana_info *LP_coinfind(char *symbol)
+struct iguana_info *LP_coinfind_flags(char *symbol,int32_t electrumonly)
 {
     struct iguana_info *coin,cdata; int32_t txversion=0,isinactive,isPoS,longestchain = 1; uint16_t port,busport; uint64_t txfee; double estimatedrate; uint8_t pubtype,p2shtype,wiftype; char *name,*assetname;
     if ( symbol == 0 || symbol[0] == 0 )
@@ -495,8 +497,8 @@ struct iguana_info *LP_coinfind(char *symbol)
         txversion = 4;
     }
     else return(0);
-    port = LP_coininit(&cdata,symbol,name,assetname,isPoS,port,pubtype,p2shtype,wiftype,txfee,estimatedrate,longestchain,0,0,busport,0,0,txversion);
-    if ( port == 0 )
+    port = LP_coininit(&cdata,symbol,name,assetname,isPoS,port,pubtype,p2shtype,wiftype,txfee,estimatedrate,longestchain,0,0,busport,0,0,txversion,electrumonly);
+    if ( port == 0 || electrumonly != 0 )
         isinactive = 1;
     else isinactive = 0;
     if ( (coin= LP_coinadd(&cdata)) != 0 )
@@ -504,7 +506,7 @@ struct iguana_info *LP_coinfind(char *symbol)
         coin->inactive = isinactive * (uint32_t)time(NULL);
         /*if ( strcmp(symbol,"KMD") == 0 )
             coin->inactive = 0;
-        else*/ if ( strcmp(symbol,"BTC") == 0 )
+        else*/ if ( strcmp(symbol,"BTC") == 0 && electrumonly == 0 )
         {
             coin->inactive = (uint32_t)time(NULL) * !IAMLP;
             printf("BTC inactive.%u\n",coin->inactive);
@@ -513,13 +515,30 @@ struct iguana_info *LP_coinfind(char *symbol)
     return(coin);
 }
 
+struct iguana_info *LP_coinfind(char *symbol)
+{
+    return(LP_coinfind_flags(symbol,0));
+}
+
 // "coins":[{"coin":"<assetchain>", "rpcport":pppp}, {"coin":"LTC", "name":"litecoin", "rpcport":9332, "pubtype":48, "p2shtype":5, "wiftype":176, "txfee":100000 }]
 // {"coin":"HUSH", "name":"hush", "rpcport":8822, "taddr":28, "pubtype":184, "p2shtype":189, "wiftype":128, "txfee":10000 }
 
 struct iguana_info *LP_coincreate(cJSON *item)
 {
-    struct iguana_info cdata,*coin=0; int32_t isPoS,longestchain = 1; uint16_t port; uint64_t txfee; double estimatedrate; uint8_t pubtype,p2shtype,wiftype; char *name=0,*symbol,*assetname=0;
-    if ( (symbol= jstr(item,"coin")) != 0 && symbol[0] != 0 && strlen(symbol) < 16 && LP_coinfind(symbol) == 0 && (port= juint(item,"rpcport")) != 0 )
+    struct iguana_info cdata,*coin=0; int32_t isPoS,electrumonly,longestchain = 1; uint16_t port; uint64_t txfee; double estimatedrate; uint8_t pubtype,p2shtype,wiftype; char *name=0,*symbol,*assetname=0;
+    electrumonly = (item != 0 && jint(item,"electrum_only") != 0);
+    if ( (symbol= jstr(item,"coin")) != 0 && symbol[0] != 0 && strlen(symbol) < 16 && (coin= LP_coinfind_flags(symbol,electrumonly)) != 0 )
+    {
+        // already known (KMD/BTC are created by LP_coinfind), only apply the electrum_only flag
+        if ( electrumonly != 0 )
+        {
+            coin->electrumonly = 1;
+            if ( coin->electrum == 0 )
+                coin->inactive = (uint32_t)time(NULL);
+        }
+        return(0);
+    }
+    else if ( symbol != 0 && symbol[0] != 0 && strlen(symbol) < 16 && (port= juint(item,"rpcport")) != 0 )
     {
         isPoS = jint(item,"isPoS");
         txfee = j64bits(item,"txfee");
@@ -543,7 +562,7 @@ struct iguana_info *LP_coincreate(cJSON *item)
         if (txversion == 0) {
             txversion = 1;
         }
-        if ( LP_coininit(&cdata,symbol,name,assetname==0?"":assetname,isPoS,port,pubtype,p2shtype,wiftype,txfee,estimatedrate,longestchain,juint(item,"wiftaddr"),juint(item,"taddr"),LP_busport(port),jstr(item,"confpath"),decimals,txversion) < 0 )
+        if ( LP_coininit(&cdata,symbol,name,assetname==0?"":assetname,isPoS,port,pubtype,p2shtype,wiftype,txfee,estimatedrate,longestchain,juint(item,"wiftaddr"),juint(item,"taddr"),LP_busport(port),jstr(item,"confpath"),decimals,txversion,electrumonly) < 0 )
         {
             coin = LP_coinadd(&cdata);
             coin->inactive = (uint32_t)time(NULL);
@@ -563,6 +582,8 @@ struct iguana_info *LP_coincreate(cJSON *item)
                 else coin->inactive = 0;
             }
         } else coin->inactive = 0;
+        if ( electrumonly != 0 )
+            coin->inactive = (uint32_t)time(NULL);
     }
     if ( 0 && coin != 0 && coin->inactive != 0 )
         printf("LPnode.%d %s inactive.%u %p vs %p\n",IAMLP,coin->symbol,coin->inactive,assetname,name);
@@ -32,6 +32,33 @@ char *LP_numutxos()
     return(jprint(retjson,1));
 }
 
+int32_t LP_profitmargin_valid(double margin)
+{
+    if ( margin < 0. || margin > LP_MAXPROFITMARGIN )
+        return(0);
+    return(1);
+}
+
+int32_t LP_drain_pendingswaps()
+{
+    int32_t pendingswaps;
+    portable_mutex_lock(&LP_pendswap_mutex);
+    pendingswaps = G.LP_pendingswaps;
+    portable_mutex_unlock(&LP_pendswap_mutex);
+    return(pendingswaps);
+}
+
+void LP_drainloop(void *arg)
+{
+    int32_t pendingswaps;
+    while ( (pendingswaps= LP_drain_pendingswaps()) > 0 && time(NULL) < LP_DRAIN_STARTED+LP_DRAIN_TIMEOUTSECS )
+        sleep(1);
+    if ( pendingswaps > 0 )
+        printf("drain timeout with %d pending swaps, stopping anyway\n",pendingswaps);
+    else printf("drain complete, stopping\n");
+    LP_STOP_RECEIVED = 1;
+}
+
 char *stats_JSON(void *ctx,int32_t fastflag,char *myipaddr,int32_t pubsock,cJSON *argjson,char *remoteaddr,uint16_t port) // from rpc port
 {
     char *method,*userpass,*base,*rel,*coin,*passphrase,*retstr = 0; int32_t authenticated=0,changed,flag = 0; cJSON *retjson,*reqjson = 0; struct iguana_info *ptr;
@@ -128,6 +155,7 @@ swapstatus(coin, limit=10)\n\
 swapstatus(base, rel, limit=10)\n\
 swapstatus(requestid, quoteid, pending=0, fast=0)\n\
 recentswaps(limit=3)\n\
+active_swaps()\n\
 kickstart(requestid, quoteid)\n\
 notarizations(coin)\n\
 public API:\n \
@@ -157,7 +185,7 @@ electrum(coin, ipaddr, port)\n\
 snapshot(coin, height)\n\
 snapshot_balance(coin, height, addresses[])\n\
 dividends(coin, height, <args>)\n\
-stop()\n\
+stop(drain=0, timeout=14400)\n\
 bot_list()\n\
 bot_statuslist()\n\
 bot_buy(base, rel, maxprice, relvolume) -> botid\n\
@@ -175,6 +203,8 @@ unlockedspend(coin, txid)\n\
 opreturndecrypt(coin, txid, passphrase)\n\
 getendpoint(port=5555)\n\
 getfee(coin)\n\
+get_profitmargin()\n\
+set_profitmargin(margin)\n\
 mpnet(onoff)\n\
 sleep(seconds=60)\n\
 listtransactions(coin, address, count=10, skip=0)\n\
@@ -184,6 +214,7 @@ version\n\
     if ( strcmp(method,"version") == 0 ) {
         retjson = cJSON_CreateObject();
         jaddstr(retjson,"result",MM_VERSION);
+        jaddstr(retjson,"gui",G.gui[0] != 0 ? G.gui : LP_gui);
         return(jprint(retjson,1));
     }
 
@@ -219,6 +250,8 @@ version\n\
         {
             char coinaddr[64],pub33str[67];
             G.USERPASS_COUNTER = 1;
+            if ( jobj(argjson,"gui") != 0 && LP_gui_valid(jstr(argjson,"gui")) == 0 )
+                return(clonestr("{\"error\":\"gui must be a printable string of 1 to 64 chars\"}"));
             if ( LP_passphrase_init(jstr(argjson,"passphrase"),jstr(argjson,"gui"),juint(argjson,"netid"),jstr(argjson,"seednode")) < 0 )
                 return(clonestr("{\"error\":\"couldnt change passphrase\"}"));
             {
@@ -336,16 +369,74 @@ version\n\
         {
             return(LP_cancel_order(jstr(argjson,"uuid")));
         }
+        else if ( strcmp(method,"active_swaps") == 0 )
+        {
+            return(LP_activeswaps_json());
+        }
         else if ( strcmp(method,"recentswaps") == 0 )
         {
             return(LP_recent_swaps(jint(argjson,"limit"),0));
         }
         else if ( strcmp(method,"stop") == 0 )
         {
+            if ( jint(argjson,"drain") != 0 )
+            {
+                int32_t pendingswaps;
+                if ( LP_DRAIN_STARTED == 0 )
+                {
+                    LP_DRAIN_TIMEOUTSECS = jint(argjson,"timeout") > 0 ? jint(argjson,"timeout") : LP_DRAIN_TIMEOUT;
+                    portable_mutex_lock(&LP_pendswap_mutex);
+                    LP_DRAIN_STARTED = (uint32_t)time(NULL);
+                    portable_mutex_unlock(&LP_pendswap_mutex);
+                    printf("stop: draining pendingswaps.%d timeout.%u\n",LP_drain_pendingswaps(),LP_DRAIN_TIMEOUTSECS);
+                    if ( OS_thread_create(malloc(sizeof(pthread_t)),NULL,(void *)LP_drainloop,0) != 0 )
+                    {
+                        printf("error launching LP_drainloop\n");
+                        LP_STOP_RECEIVED = 1;
+                    }
+                }
+                retjson = cJSON_CreateObject();
+                jaddstr(retjson,"result","success");
+                pendingswaps = LP_drain_pendingswaps();
+                jaddstr(retjson,"status",pendingswaps > 0 ? "draining" : "drained");
+                jaddnum(retjson,"pendingswaps",pendingswaps);
+                jaddnum(retjson,"drainstarted",LP_DRAIN_STARTED);
+                jaddnum(retjson,"timeout",LP_DRAIN_TIMEOUTSECS);
+                return(jprint(retjson,1));
+            }
             printf("DEBUG stop\n");
             LP_STOP_RECEIVED = 1;
             return(clonestr("{\"result\":\"success\"}"));
         }
+        else if ( strcmp(method,"get_profitmargin") == 0 )
+        {
+            double profitratio = LP_profitratio_get();
+            retjson = cJSON_CreateObject();
+            jaddstr(retjson,"result","success");
+            jaddnum(retjson,"profitmargin",profitratio - 1.);
+            jaddnum(retjson,"profitratio",profitratio);
+            return(jprint(retjson,1));
+        }
+        else if ( strcmp(method,"set_profitmargin") == 0 )
+        {
+            double margin,profitratio;
+            if ( jobj(argjson,"margin") == 0 )
+                return(clonestr("{\"error\":\"set_profitmargin needs margin\"}"));
+            margin = jdouble(argjson,"margin");
+            if ( LP_profitmargin_valid(margin) == 0 )
+            {
+                char errstr[128];
+                sprintf(errstr,"{\"error\":\"margin must be between 0 and %.8f\"}",LP_MAXPROFITMARGIN);
+                return(clonestr(errstr));
+            }
+            profitratio = LP_profitratio_set(margin);
+            printf("profitmargin set to %.8f\n",margin);
+            retjson = cJSON_CreateObject();
+            jaddstr(retjson,"result","success");
+            jaddnum(retjson,"profitmargin",margin);
+            jaddnum(retjson,"profitratio",profitratio);
+            return(jprint(retjson,1));
+        }
         else if ( strcmp(method,"millis") == 0 )
         {
             LP_millistats_update(0);
@@ -547,7 +638,7 @@ version\n\
                 //else if ( LP_mypriceset(1,&changed,rel,base,1./price) < 0 )
                 //    return(clonestr("{\"error\":\"couldnt set price\"}"));
                 else if ( price == 0. || jobj(argjson,"broadcast") == 0 || jint(argjson,"broadcast") != 0 )
-                    return(LP_pricepings(ctx,myipaddr,LP_mypubsock,base,rel,price * LP_profitratio));
+                    return(LP_pricepings(ctx,myipaddr,LP_mypubsock,base,rel,price * LP_profitratio_get()));
                 else return(clonestr("{\"result\":\"success\"}"));
             }
             else if ( strcmp(method,"myprice") == 0 )
@@ -605,6 +696,13 @@ version\n\
                 //*
                 if ( (ptr= LP_coinsearch(coin)) != 0 )
                 {
+                    if ( ptr->electrumonly != 0 )
+                    {
+                        cJSON *retjson = cJSON_CreateObject();
+                        jaddstr(retjson,"error","coin is electrum_only, use the electrum method to enable it");
+                        jaddstr(retjson,"coin",coin);
+                        return(jprint(retjson,1));
+                    }
                     if ( ptr->userpass[0] == 0 && ptr->etomic[0] == 0 )
                     {
                         cJSON *retjson = cJSON_CreateObject();
@@ -59,6 +59,9 @@ void emscripten_usleep(int32_t x); // returns immediate, no sense for sleeping
 //#define LP_DISABLE_DISTCOMBINE
 
 #define LP_MAXVINS 64
+#define LP_MAXPROFITMARGIN 1.
+#define LP_DRAIN_TIMEOUT (3600 * 4)
+#define LP_MAXACTIVESWAPS 256
 #define LP_HTTP_TIMEOUT 10 // 1 is too small due to edge cases of time(NULL)
 #define LP_AUTOTRADE_TIMEOUT 30
 #define LP_RESERVETIME (LP_AUTOTRADE_TIMEOUT * 3)
@@ -342,7 +345,7 @@ struct iguana_info
     uint64_t txfee,do_autofill_merge;
     int32_t numutxos,notarized,longestchain,firstrefht,firstscanht,lastscanht,height; uint16_t busport,did_addrutxo_reset;
     uint32_t txversion,dPoWtime,lastautosplit,lastresetutxo,loadedcache,electrumlist,lastunspent,importedprivkey,lastpushtime,lastutxosync,addr_listunspent_requested,lastutxos,updaterate,counter,inactive,lastmempool,lastgetinfo,ratetime,heighttime,lastmonitor,obooktime;
-    uint8_t pubtype,p2shtype,isPoS,wiftype,wiftaddr,taddr,noimportprivkey_flag,userconfirms,isassetchain,maxconfirms,cache_history;
+    uint8_t pubtype,p2shtype,isPoS,wiftype,wiftaddr,taddr,noimportprivkey_flag,userconfirms,isassetchain,maxconfirms,cache_history,electrumonly;
     char symbol[128],smartaddr[64],userpass[1024],serverport[128],instantdex_address[64],estimatefeestr[32],getinfostr[32],etomic[64],validateaddress[64];
     struct LP_tx_history_item *tx_history;
     // portfolio
@@ -545,6 +548,7 @@ uint64_t LP_RTsmartbalance(struct iguana_info *coin);
 int32_t LP_getheight(int32_t *notarizedp,struct iguana_info *coin);
 int32_t LP_reserved_msg(int32_t priority,char *base,char *rel,bits256 pubkey,char *msg);
 struct iguana_info *LP_coinfind(char *symbol);
+struct iguana_info *LP_coinfind_flags(char *symbol,int32_t electrumonly);
 int32_t LP_crc32find(int32_t *duplicatep,int32_t ind,uint32_t crc32);
 char *LP_pricepings(void *ctx,char *myipaddr,int32_t pubsock,char *base,char *rel,double price);
 int32_t LP_merkleproof(struct iguana_info *coin,char *coinaddr,struct electrum_info *ep,bits256 txid,int32_t height);
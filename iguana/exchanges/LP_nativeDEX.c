@@ -93,7 +93,7 @@ void LP_millistats_update(struct LP_millistats *mp)
 #include "LP_etomic.h"
 #endif
 
-portable_mutex_t LP_peermutex,LP_UTXOmutex,LP_utxomutex,LP_commandmutex,LP_cachemutex,LP_swaplistmutex,LP_forwardmutex,LP_pubkeymutex,LP_networkmutex,LP_psockmutex,LP_coinmutex,LP_messagemutex,LP_portfoliomutex,LP_electrummutex,LP_butxomutex,LP_reservedmutex,LP_nanorecvsmutex,LP_tradebotsmutex,LP_gcmutex,LP_inusemutex,LP_cJSONmutex,LP_logmutex,LP_statslogmutex,LP_tradesmutex,LP_commandQmutex,LP_blockinit_mutex,LP_pendswap_mutex,LP_listmutex,LP_gtcmutex;
+portable_mutex_t LP_peermutex,LP_UTXOmutex,LP_utxomutex,LP_commandmutex,LP_cachemutex,LP_swaplistmutex,LP_forwardmutex,LP_pubkeymutex,LP_networkmutex,LP_psockmutex,LP_coinmutex,LP_messagemutex,LP_portfoliomutex,LP_electrummutex,LP_butxomutex,LP_reservedmutex,LP_nanorecvsmutex,LP_tradebotsmutex,LP_gcmutex,LP_inusemutex,LP_cJSONmutex,LP_logmutex,LP_statslogmutex,LP_tradesmutex,LP_commandQmutex,LP_blockinit_mutex,LP_pendswap_mutex,LP_listmutex,LP_gtcmutex,LP_profitmutex;
 int32_t LP_canbind;
 char *Broadcaststr,*Reserved_msgs[2][1000];
 int32_t num_Reserved_msgs[2],max_Reserved_msgs[2];
@@ -108,7 +108,7 @@ struct LP_trade *LP_trades,*LP_tradesQ;
 //uint32_t LP_deadman_switch;
 uint16_t LP_fixed_pairport;//,LP_publicport;
 uint32_t LP_lastnonce,LP_swap_endcritical,LP_swap_critical,LP_RTcount,LP_swapscount;
-int32_t LP_STOP_RECEIVED,LP_numactive_LP;//,LP_mybussock = -1;
+int32_t LP_STOP_RECEIVED,LP_numactive_LP; uint32_t LP_DRAIN_STARTED,LP_DRAIN_TIMEOUTSECS,LP_maxswaps;//,LP_mybussock = -1;
 int32_t LP_mypubsock = -1,IPC_ENDPOINT = -1;
 int32_t LP_cmdcount,LP_mypullsock = -1;
 int32_t LP_numfinished,LP_showwif,IAMLP = 0;
@@ -139,6 +139,24 @@ uint32_t LP_rand()
     return(retval);
 }
 
+double LP_profitratio_get()
+{
+    double profitratio;
+    portable_mutex_lock(&LP_profitmutex);
+    profitratio = LP_profitratio;
+    portable_mutex_unlock(&LP_profitmutex);
+    return(profitratio);
+}
+
+double LP_profitratio_set(double margin)
+{
+    double profitratio;
+    portable_mutex_lock(&LP_profitmutex);
+    profitratio = LP_profitratio = 1. + margin;
+    portable_mutex_unlock(&LP_profitmutex);
+    return(profitratio);
+}
+
 #include "LP_network.c"
 
 char *activecoins[] = { "BTC", "KMD" };
@@ -480,7 +498,7 @@ int32_t LP_nanomsg_recvs(void *ctx)
      if ( coin->inactive != 0 )
      continue;
      if ( coin->bussock >= 0 )
-     nonz += LP_sock_check(coin->symbol,ctx,origipaddr,-1,coin->bussock,LP_profitratio - 1.);
+     nonz += LP_sock_check(coin->symbol,ctx,origipaddr,-1,coin->bussock,LP_profitratio_get() - 1.);
      }*/
     if ( LP_mypullsock >= 0 )
     {
@@ -943,15 +961,24 @@ void emc2addrs()
 
 void LP_initcoins(void *ctx,int32_t pubsock,cJSON *coins)
 {
-    int32_t i,n,notarized; cJSON *item; char *symbol,*etomic; struct iguana_info *coin;
+    int32_t i,j,n,notarized,electrumonly; cJSON *item; char *symbol,*etomic; struct iguana_info *coin;
+    n = cJSON_GetArraySize(coins);
     for (i=0; i<sizeof(activecoins)/sizeof(*activecoins); i++)
     {
         printf("%s, ",activecoins[i]);
-        LP_coinfind(activecoins[i]);
+        for (electrumonly=j=0; j<n; j++)
+        {
+            item = jitem(coins,j);
+            if ( (symbol= jstr(item,"coin")) != 0 && strcmp(symbol,activecoins[i]) == 0 )
+                electrumonly = (jint(item,"electrum_only") != 0);
+        }
+        LP_coinfind_flags(activecoins[i],electrumonly);
         LP_priceinfoadd(activecoins[i]);
         if ( (coin= LP_coinfind(activecoins[i])) != 0 )
         {
-            if ( LP_getheight(&notarized,coin) <= 0 )
+            if ( coin->electrumonly != 0 )
+                coin->inactive = (uint32_t)time(NULL);
+            else if ( LP_getheight(&notarized,coin) <= 0 )
                 coin->inactive = (uint32_t)time(NULL);
             else
             {
@@ -980,7 +1007,7 @@ void LP_initcoins(void *ctx,int32_t pubsock,cJSON *coins)
                 {
                     if ( (etomic= jstr(item,"etomic")) != 0 )
                         safecopy(coin->etomic,etomic,sizeof(coin->etomic));
-                    else
+                    else if ( coin->electrumonly == 0 )
                     {
                         if ( LP_getheight(&notarized,coin) <= 0 )
                             coin->inactive = (uint32_t)time(NULL);
@@ -1463,8 +1490,19 @@ void LPinit(uint16_t myport,uint16_t mypullport,uint16_t mypubport,uint16_t mybu
         printf("LP_MAXPRICEINFOS %d wont fit in a uint8_t, need to increase the width of the baseind and relind for struct LP_pubkey_quote\n",LP_MAXPRICEINFOS);
         exit(-1);
     }
+    if ( jobj(argjson,"gui") != 0 )
+    {
+        if ( LP_gui_valid(jstr(argjson,"gui")) == 0 )
+        {
+            printf("gui must be a printable string of 1 to %d chars\n",(int32_t)sizeof(LP_gui)-1);
+            exit(-1);
+        }
+        safecopy(LP_gui,jstr(argjson,"gui"),sizeof(LP_gui));
+    }
     LP_showwif = juint(argjson,"wif");
-    printf("showwif.%d version: %s %u\n",LP_showwif,MM_VERSION,calc_crc32(0,MM_VERSION,(int32_t)strlen(MM_VERSION)));
+    if ( (LP_maxswaps= juint(argjson,"max_concurrent_swaps")) > LP_MAXACTIVESWAPS )
+        LP_maxswaps = LP_MAXACTIVESWAPS;
+    printf("showwif.%d version: %s %u gui.(%s)\n",LP_showwif,MM_VERSION,calc_crc32(0,MM_VERSION,(int32_t)strlen(MM_VERSION)),LP_gui);
     if ( passphrase == 0 || passphrase[0] == 0 )
     {
         printf("jeezy says we cant use the nullstring as passphrase and I agree\n");
@@ -1480,8 +1518,6 @@ void LPinit(uint16_t myport,uint16_t mypullport,uint16_t mypubport,uint16_t mybu
 #endif
     OS_randombytes((void *)&n,sizeof(n));
     srand((uint32_t)n);
-    if ( jobj(argjson,"gui") != 0 )
-        safecopy(LP_gui,jstr(argjson,"gui"),sizeof(LP_gui));
     if ( jobj(argjson,"canbind") == 0 )
     {
 #ifndef __linux__
@@ -1536,6 +1572,7 @@ void LPinit(uint16_t myport,uint16_t mypullport,uint16_t mypubport,uint16_t mybu
     portable_mutex_init(&LP_pendswap_mutex);
     portable_mutex_init(&LP_listmutex);
     portable_mutex_init(&LP_gtcmutex);
+    portable_mutex_init(&LP_profitmutex);
     myipaddr = clonestr("127.0.0.1");
 #ifndef _WIN32
 #ifndef FROM_JS
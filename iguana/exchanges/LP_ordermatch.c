@@ -521,7 +521,12 @@ int32_t LP_connectstartbob(void *ctx,int32_t pubsock,char *base,char *rel,double
         if ( (pair= LP_nanobind(ctx,pairstr)) >= 0 )
         {
             swap->N.pair = pair;
-            if ( OS_thread_create(malloc(sizeof(pthread_t)),NULL,(void *)LP_bobloop,(void *)swap) == 0 )
+            if ( LP_activeswap_add(swap) < 0 )
+            {
+                LP_failedmsg(qp->R.requestid,qp->R.quoteid,-3005,qp->uuidstr);
+                printf("max_concurrent_swaps reached, dont start swap %u-%u\n",qp->R.requestid,qp->R.quoteid);
+            }
+            else if ( OS_thread_create(malloc(sizeof(pthread_t)),NULL,(void *)LP_bobloop,(void *)swap) == 0 )
             {
                 reqjson = LP_quotejson(qp);
                 LP_swapsfp_update(qp->R.requestid,qp->R.quoteid);
@@ -566,6 +571,7 @@ int32_t LP_connectstartbob(void *ctx,int32_t pubsock,char *base,char *rel,double
             }
             else
             {
+                LP_activeswap_remove(swap);
                 LP_failedmsg(qp->R.requestid,qp->R.quoteid,-3002,qp->uuidstr);
                 printf("error launching swaploop\n");
             }
@@ -847,7 +853,13 @@ char *LP_connectedalice(struct LP_quoteinfo *qp,char *pairstr) // alice
             LP_importaddress(qp->srccoin,otheraddr);
             LP_aliceid(qp->tradeid,qp->aliceid,"started",qp->R.requestid,qp->R.quoteid);
             printf("alice pairstr.(%s) pairsock.%d pthread_t %ld\n",pairstr,pairsock,sizeof(pthread_t));
-            if ( OS_thread_create(malloc(sizeof(pthread_t)),NULL,(void *)LP_aliceloop,(void *)swap) == 0 )
+            if ( LP_activeswap_add(swap) < 0 )
+            {
+                LP_aliceid(qp->tradeid,qp->aliceid,"error12",qp->R.requestid,qp->R.quoteid);
+                jaddstr(retjson,"error","too many active swaps, max_concurrent_swaps reached");
+                LP_failedmsg(qp->R.requestid,qp->R.quoteid,-4009,qp->uuidstr);
+            }
+            else if ( OS_thread_create(malloc(sizeof(pthread_t)),NULL,(void *)LP_aliceloop,(void *)swap) == 0 )
             {
                 retjson = LP_quotejson(qp);
                 jaddstr(retjson,"result","success");
@@ -863,6 +875,7 @@ char *LP_connectedalice(struct LP_quoteinfo *qp,char *pairstr) // alice
             }
             else
             {
+                LP_activeswap_remove(swap);
                 LP_aliceid(qp->tradeid,qp->aliceid,"error9",qp->R.requestid,qp->R.quoteid);
                 jaddstr(retjson,"error","couldnt aliceloop");
                 LP_failedmsg(qp->R.requestid,qp->R.quoteid,-4006,qp->uuidstr);
@@ -1046,6 +1059,16 @@ struct LP_quoteinfo *LP_trades_gotrequest(void *ctx,struct LP_quoteinfo *qp,stru
     *newqp = *qp;
     qp = newqp;
 printf("bob %s received REQUEST.(%s) mpnet.%d fill.%d gtc.%d\n",bits256_str(str,G.LP_mypub25519),qp->uuidstr+32,qp->mpnet,qp->fill,qp->gtc);
+    if ( LP_DRAIN_STARTED != 0 )
+    {
+        printf("draining, ignore request\n");
+        return(0);
+    }
+    if ( LP_maxswaps_reached() != 0 )
+    {
+        printf("max_concurrent_swaps.%u reached, ignore request\n",LP_maxswaps);
+        return(0);
+    }
     if ( (coin= LP_coinfind(qp->srccoin)) == 0 || (othercoin= LP_coinfind(qp->destcoin)) == 0 )
         return(0);
     if ( (myprice= LP_trades_bobprice(&bid,&ask,qp)) == 0. )
@@ -1689,6 +1712,10 @@ char *LP_autobuy(void *ctx,int32_t fomoflag,char *myipaddr,int32_t mypubsock,cha
         return(clonestr("{\"error\":\"base or rel not found or inactive\"}"));
     if ( LP_aliceonly(base) > 0 )
         return(clonestr("{\"error\":\"GAME can only be alice coin\"}"));
+    if ( LP_DRAIN_STARTED != 0 )
+        return(clonestr("{\"error\":\"draining before stop, not accepting new orders\"}"));
+    if ( LP_maxswaps_reached() != 0 )
+        return(clonestr("{\"error\":\"too many active swaps, max_concurrent_swaps reached\"}"));
     printf("LP_autobuy %s/%s price %.8f vol %.8f nonce %u\n",base,rel,maxprice,relvolume,nonce);
     if ( (lastnonce= LP_lastnonce) != 0 && nonce <= lastnonce )
     {
@@ -711,6 +711,17 @@ void LP_privkey_updates(void *ctx,int32_t pubsock,char *passphrase)
     }
 }
 
+int32_t LP_gui_valid(char *gui)
+{
+    int32_t i,len;
+    if ( gui == 0 || (len= (int32_t)strlen(gui)) == 0 || len >= (int32_t)sizeof(G.gui) )
+        return(0);
+    for (i=0; i<len; i++)
+        if ( isprint((int32_t)gui[i]) == 0 || gui[i] == '"' || gui[i] == '\\' )
+            return(0);
+    return(1);
+}
+
 int32_t LP_passphrase_init(char *passphrase,char *gui,uint16_t netid,char *seednode)
 {
     static void *ctx; struct iguana_info *coin,*tmp; int32_t counter;
@@ -756,7 +767,11 @@ int32_t LP_passphrase_init(char *passphrase,char *gui,uint16_t netid,char *seedn
     calc_rmd160_sha256(G.LP_myrmd160, pubkey33, 33);
     init_hexbytes_noT(G.LP_myrmd160str,G.LP_myrmd160,20);
     G.LP_sessionid = (uint32_t)time(NULL);
-    safecopy(G.gui,gui,sizeof(G.gui));
+    if ( gui != 0 )
+        safecopy(G.gui,gui,sizeof(G.gui));
+    else if ( G.gui[0] == 0 )
+        safecopy(G.gui,LP_gui,sizeof(G.gui));
+    safecopy(LP_gui,G.gui,sizeof(LP_gui));
     LP_tradebot_pauseall();
     LP_portfolio_reset();
     LP_priceinfos_clear();
@@ -842,10 +842,96 @@ int32_t LP_calc_waittimeout(char *symbol)
     return(waittimeout);
 }
 
+struct basilisk_swap *LP_activeswaps[LP_MAXACTIVESWAPS];
+
+int32_t LP_maxswaps_limit()
+{
+    if ( LP_maxswaps != 0 && LP_maxswaps < LP_MAXACTIVESWAPS )
+        return((int32_t)LP_maxswaps);
+    return(LP_MAXACTIVESWAPS);
+}
+
+int32_t LP_activeswap_add(struct basilisk_swap *swap)
+{
+    int32_t i,retval = -1;
+    portable_mutex_lock(&LP_pendswap_mutex);
+    if ( LP_DRAIN_STARTED == 0 && G.LP_pendingswaps < LP_maxswaps_limit() )
+    {
+        for (i=0; i<LP_MAXACTIVESWAPS; i++)
+        {
+            if ( LP_activeswaps[i] == 0 )
+            {
+                LP_activeswaps[i] = swap;
+                G.LP_pendingswaps++;
+                retval = 0;
+                break;
+            }
+        }
+    }
+    portable_mutex_unlock(&LP_pendswap_mutex);
+    if ( retval < 0 )
+        printf("no active swap slot, pendingswaps.%d max_concurrent_swaps.%u draining.%u\n",G.LP_pendingswaps,LP_maxswaps,LP_DRAIN_STARTED);
+    return(retval);
+}
+
+void LP_activeswap_remove(struct basilisk_swap *swap)
+{
+    int32_t i;
+    portable_mutex_lock(&LP_pendswap_mutex);
+    for (i=0; i<LP_MAXACTIVESWAPS; i++)
+    {
+        if ( LP_activeswaps[i] == swap )
+        {
+            LP_activeswaps[i] = 0;
+            G.LP_pendingswaps--;
+            break;
+        }
+    }
+    portable_mutex_unlock(&LP_pendswap_mutex);
+}
+
+int32_t LP_maxswaps_reached()
+{
+    if ( G.LP_pendingswaps >= LP_maxswaps_limit() )
+        return(1);
+    return(0);
+}
+
+char *LP_activeswaps_json()
+{
+    int32_t i,pendingswaps; struct basilisk_swap *swap; cJSON *retjson,*array,*item;
+    array = cJSON_CreateArray();
+    portable_mutex_lock(&LP_pendswap_mutex);
+    for (i=0; i<LP_MAXACTIVESWAPS; i++)
+    {
+        if ( (swap= LP_activeswaps[i]) != 0 )
+        {
+            item = cJSON_CreateObject();
+            jaddstr(item,"uuid",swap->uuidstr);
+            jaddnum(item,"requestid",swap->I.req.requestid);
+            jaddnum(item,"quoteid",swap->I.req.quoteid);
+            jaddnum(item,"iambob",swap->I.iambob);
+            jaddstr(item,"bob",swap->I.bobstr);
+            jaddstr(item,"alice",swap->I.alicestr);
+            jaddnum(item,"statebits",swap->I.statebits);
+            jaddnum(item,"started",swap->I.started);
+            jaddnum(item,"expiration",swap->I.expiration);
+            jaddi(array,item);
+        }
+    }
+    pendingswaps = G.LP_pendingswaps;
+    portable_mutex_unlock(&LP_pendswap_mutex);
+    retjson = cJSON_CreateObject();
+    jaddstr(retjson,"result","success");
+    jaddnum(retjson,"pendingswaps",pendingswaps);
+    jaddnum(retjson,"max_concurrent_swaps",LP_maxswaps);
+    jadd(retjson,"swaps",array);
+    return(jprint(retjson,1));
+}
+
 void LP_bobloop(void *_swap)
 {
     uint8_t *data; char bobstr[65],alicestr[65]; int32_t bobwaittimeout,alicewaittimeout,maxlen,m,n,err=0; uint32_t expiration; struct basilisk_swap *swap = _swap;
-    G.LP_pendingswaps++;
     //printf("start swap iambob\n");
     LP_etomicsymbol(bobstr,swap->I.bobtomic,swap->I.bobstr);
     LP_etomicsymbol(alicestr,swap->I.alicetomic,swap->I.alicestr);
@@ -950,16 +1036,15 @@ void LP_bobloop(void *_swap)
     LP_pendswap_add(swap->I.expiration,swap->I.req.requestid,swap->I.req.quoteid);
     //swap->I.finished = LP_swapwait(swap->I.expiration,swap->I.req.requestid,swap->I.req.quoteid,LP_atomic_locktime(swap->I.bobstr,swap->I.alicestr)*3,swap->I.aliceconfirms == 0 ? 3 : 30);
     basilisk_swap_finished(swap);
+    LP_activeswap_remove(swap);
     free(swap);
     free(data);
-    G.LP_pendingswaps--;
 }
 
 void LP_aliceloop(void *_swap)
 {
     uint8_t *data; char bobstr[65],alicestr[65]; int32_t bobwaittimeout,alicewaittimeout,maxlen,n,m,err=0; uint32_t expiration; struct basilisk_swap *swap = _swap;
     LP_alicequery_clear();
-    G.LP_pendingswaps++;
     LP_etomicsymbol(bobstr,swap->I.bobtomic,swap->I.bobstr);
     LP_etomicsymbol(alicestr,swap->I.alicetomic,swap->I.alicestr);
     maxlen = 1024*1024 + sizeof(*swap);
@@ -1047,9 +1132,9 @@ void LP_aliceloop(void *_swap)
     LP_pendswap_add(swap->I.expiration,swap->I.req.requestid,swap->I.req.quoteid);
     //swap->I.finished = LP_swapwait(swap->I.expiration,swap->I.req.requestid,swap->I.req.quoteid,LP_atomic_locktime(swap->I.bobstr,swap->I.alicestr)*3,swap->I.aliceconfirms == 0 ? 3 : 30);
     basilisk_swap_finished(swap);
+    LP_activeswap_remove(swap);
     free(swap);
     free(data);
-    G.LP_pendingswaps--;
 }
 
 bits256 instantdex_derivekeypair(void *ctx,bits256 *newprivp,uint8_t pubkey[33],bits256 privkey,bits256 orderhash)
@@ -103,10 +103,36 @@ void LP_ports(uint16_t *pullportp,uint16_t *pubportp,uint16_t *busportp,uint16_t
 
 void LP_main(void *ptr)
 {
-    char *passphrase; double profitmargin; uint16_t netid=0,port,pullport,pubport,busport; cJSON *argjson = ptr;
+    char *passphrase,*passfile,*filestr = 0; long filesize; int32_t i; double profitmargin; uint16_t netid=0,port,pullport,pubport,busport; cJSON *argjson = ptr;
     if ( (passphrase= jstr(argjson,"passphrase")) != 0 )
+        printf("using passphrase from config\n");
+    else if ( (passfile= jstr(argjson,"passphrase_file")) != 0 )
+    {
+        if ( (filestr= OS_filestr(&filesize,passfile)) != 0 )
+        {
+            for (i=(int32_t)strlen(filestr)-1; i>=0; i--)
+                if ( filestr[i] == '\n' || filestr[i] == '\r' )
+                    filestr[i] = 0;
+                else break;
+            passphrase = filestr;
+            printf("using passphrase from passphrase_file (%s)\n",passfile);
+        } else printf("couldnt read passphrase_file (%s)\n",passfile);
+    }
+    else if ( (passphrase= getenv("MM2_PASSPHRASE")) != 0 )
+        printf("using passphrase from MM2_PASSPHRASE\n");
+    if ( passphrase == 0 || passphrase[0] == 0 )
+    {
+        printf("!passphrase, set passphrase, passphrase_file or MM2_PASSPHRASE\n");
+        exit(-1);
+    }
+    else
     {
         profitmargin = jdouble(argjson,"profitmargin");
+        if ( LP_profitmargin_valid(profitmargin) == 0 )
+        {
+            printf("profitmargin %.8f must be between 0 and %.8f, clamped\n",profitmargin,LP_MAXPROFITMARGIN);
+            profitmargin = (profitmargin < 0.) ? 0. : LP_MAXPROFITMARGIN;
+        }
         LP_profitratio += profitmargin;
         if ( (port= juint(argjson,"rpcport")) < 1000 )
             port = LP_RPCPORT;
@@ -115,6 +141,8 @@ void LP_main(void *ptr)
         LP_ports(&pullport,&pubport,&busport,netid);
         LPinit(port,pullport,pubport,busport,passphrase,jint(argjson,"client"),jstr(argjson,"userhome"),argjson);
     }
+    if ( filestr != 0 )
+        free(filestr);
 }
 
 int32_t ensure_writable(char *dirname)
@@ -372,9 +400,22 @@ int main(int argc, const char * argv[])
     LP_main(retjson);
     emscripten_set_main_loop(LP_fromjs_iter,1,0);
 #else
-    if ( argc == 1 )
+    if ( argc == 1 || ((argc == 2 || argc == 3) && strcmp(argv[1],"nxt") == 0) )
     {
         //LP_privkey_tests();
+        if ( argc == 3 )
+        {
+            char *portstr,*endptr = 0; long nxtport = 0;
+            if ( (portstr= strrchr(argv[2],':')) != 0 && portstr != argv[2] && isdigit((int32_t)portstr[1]) != 0 )
+                nxtport = strtol(portstr+1,&endptr,10);
+            if ( endptr == 0 || *endptr != 0 || nxtport <= 0 || nxtport > 65535 || strlen(argv[2]) >= sizeof(LP_NXTendpoint) || strpbrk(argv[2],"/?#@ \t\r\n") != 0 )
+            {
+                printf("invalid NXT endpoint.(%s), usage: nxt host:port\n",argv[2]);
+                return(-1);
+            }
+            safecopy(LP_NXTendpoint,(char *)argv[2],sizeof(LP_NXTendpoint));
+        }
+        printf("querying NXT client at %s\n",LP_NXTendpoint);
         LP_NXT_redeems();
         sleep(3);
         return(0);